@@ -0,0 +1,81 @@
+// Close prompt: answers the backend's "close-requested" event when the close behavior is "ask"
+// Shared by the login and settings windows; builds its own dialog so neither page needs markup for it
+
+(function () {
+    if (!window.__TAURI__?.event?.listen) {
+        return;
+    }
+
+    let closePromptModal = null;
+    let pendingLabel = null;
+
+    function buildClosePrompt() {
+        const modal = document.createElement('div');
+        modal.className = 'close-prompt-modal';
+        modal.innerHTML = `
+            <div class="close-prompt-content">
+                <h3 class="close-prompt-title">Close EasyCLI?</h3>
+                <p class="close-prompt-message">Keep EasyCLI running in the tray, or quit the app?</p>
+                <label class="close-prompt-remember">
+                    <input type="checkbox" id="close-prompt-remember"> Remember my choice
+                </label>
+                <div class="close-prompt-actions">
+                    <button type="button" class="close-prompt-btn" data-action="cancel">Cancel</button>
+                    <button type="button" class="close-prompt-btn" data-action="minimize">Minimize to Tray</button>
+                    <button type="button" class="close-prompt-btn close-prompt-primary" data-action="exit">Quit</button>
+                </div>
+            </div>`;
+        modal.addEventListener('click', (e) => {
+            if (e.target === modal) {
+                hideClosePrompt();
+                return;
+            }
+            const action = e.target?.dataset?.action;
+            if (action) {
+                handleCloseAction(action);
+            }
+        });
+        document.body.appendChild(modal);
+        return modal;
+    }
+
+    function hideClosePrompt() {
+        if (closePromptModal) {
+            closePromptModal.classList.remove('show');
+        }
+        pendingLabel = null;
+    }
+
+    async function handleCloseAction(action) {
+        const label = pendingLabel;
+        const remember = document.getElementById('close-prompt-remember')?.checked || false;
+        hideClosePrompt();
+        if (action === 'cancel' || !label) {
+            return;
+        }
+        try {
+            await window.__TAURI__.core.invoke('resolve_close_request', { label, action, remember });
+        } catch (error) {
+            console.error('Error resolving close request:', error);
+        }
+    }
+
+    window.__TAURI__.event.listen('close-requested', (event) => {
+        const data = event?.payload || {};
+        if (!closePromptModal) {
+            closePromptModal = buildClosePrompt();
+        }
+        pendingLabel = data.label;
+        const remember = document.getElementById('close-prompt-remember');
+        if (remember) {
+            remember.checked = false;
+        }
+        closePromptModal.classList.add('show');
+    });
+
+    document.addEventListener('keydown', (e) => {
+        if (e.key === 'Escape' && closePromptModal?.classList.contains('show')) {
+            hideClosePrompt();
+        }
+    });
+})();
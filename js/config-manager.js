@@ -2,6 +2,10 @@
  * Configuration Manager Abstraction Layer
  * Unified operation interface for Local and Remote modes
  */
+
+// Result returned by write methods while read-only mode is on
+const READ_ONLY_RESULT = Object.freeze({ success: false, error: 'EasyCLI is in read-only mode' });
+
 class ConfigManager {
     constructor() {
         this.type = localStorage.getItem('type') || 'local';
@@ -77,6 +81,22 @@ class ConfigManager {
         }
     }
 
+    /**
+     * Check whether EasyCLI is in read-only mode. Local writes are refused by
+     * the backend, but remote writes go straight to the management API, so
+     * every write method checks here first.
+     * @returns {Promise<boolean>} Whether writes are blocked
+     */
+    async isReadOnly() {
+        try {
+            const state = await window.__TAURI__.core.invoke('get_read_only_mode');
+            return !!(state && state.enabled);
+        } catch (error) {
+            console.error('Error reading read-only mode:', error);
+            return false;
+        }
+    }
+
     /**
      * Update configuration item
      * @param {string} endpoint - Configuration item path
@@ -85,6 +105,7 @@ class ConfigManager {
      * @returns {Promise<boolean>} Whether operation was successful
      */
     async updateSetting(endpoint, value, isDelete = false) {
+        if (await this.isReadOnly()) return false;
         if (this.type === 'local') {
             return this.updateLocalSetting(endpoint, value, isDelete);
         } else {
@@ -112,6 +133,7 @@ class ConfigManager {
      * @returns {Promise<boolean>} Whether operation was successful
      */
     async updateApiKeys(keyType, keys) {
+        if (await this.isReadOnly()) return false;
         if (this.type === 'local') {
             return this.updateLocalApiKeys(keyType, keys);
         } else {
@@ -137,6 +159,7 @@ class ConfigManager {
      * @returns {Promise<Object>} Upload result
      */
     async uploadAuthFiles(files) {
+        if (await this.isReadOnly()) return READ_ONLY_RESULT;
         if (this.type === 'local') {
             return this.uploadLocalAuthFiles(files);
         } else {
@@ -150,6 +173,7 @@ class ConfigManager {
      * @returns {Promise<Object>} Delete result
      */
     async deleteAuthFiles(filenames) {
+        if (await this.isReadOnly()) return READ_ONLY_RESULT;
         if (this.type === 'local') {
             return this.deleteLocalAuthFiles(filenames);
         } else {
@@ -178,6 +202,7 @@ class ConfigManager {
      * @returns {Promise<Object>} Save result
      */
     async saveGeminiWebTokens(secure1psid, secure1psidts, email) {
+        if (await this.isReadOnly()) return READ_ONLY_RESULT;
         console.log('=== DEBUG: saveGeminiWebTokens ===');
         console.log('this.type:', this.type);
         if (this.type === 'local') {
@@ -193,6 +218,7 @@ class ConfigManager {
      * @returns {Promise<Object>} Save result
      */
     async saveIFlowCookie(cookie) {
+        if (await this.isReadOnly()) return READ_ONLY_RESULT;
         if (!cookie || !cookie.trim()) {
             return { success: false, error: 'Cookie is required' };
         }
@@ -210,6 +236,7 @@ class ConfigManager {
      * @returns {Promise<Object>} Import result
      */
     async importVertexCredential(file, location = 'us-central1') {
+        if (await this.isReadOnly()) return READ_ONLY_RESULT;
         if (!file) {
             return { success: false, error: 'No file selected' };
         }
@@ -590,7 +617,7 @@ class ConfigManager {
                             const saveRes = await window.__TAURI__.core.invoke('save_files_to_directory', { files: result.files });
                             return saveRes;
                         } catch (error) {
-                            if (String(error).includes('User cancelled directory selection')) {
+                            if (error?.code === 'cancelled') {
                                 return { success: false, error: 'User cancelled directory selection' };
                             }
                             console.error('Tauri save_files_to_directory failed, falling back:', error);
@@ -1052,7 +1079,7 @@ class ConfigManager {
                     const saveRes = await window.__TAURI__.core.invoke('save_files_to_directory', { files });
                     return saveRes;
                 } catch (error) {
-                    if (String(error).includes('User cancelled directory selection')) {
+                    if (error?.code === 'cancelled') {
                         return { success: false, error: 'User cancelled directory selection' };
                     }
                     console.error('Tauri save_files_to_directory fallback failed; trying browser options:', error);
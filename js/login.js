@@ -502,6 +502,17 @@ async function handleConnectClick() {
 if (continueBtn) {
     continueBtn.addEventListener('click', handleConnectClick);
 }
+
+// auto-start-proxy: connect with the last used mode once per app launch
+(async () => {
+    try {
+        if (!localStorage.getItem('type')) return;
+        const res = await window.__TAURI__?.core?.invoke('take_launch_auto_start');
+        if (res?.start) handleConnectClick();
+    } catch (e) {
+        console.error('take_launch_auto_start failed:', e);
+    }
+})();
 // Provide a global fallback for inline onclick
 window.__onConnect = handleConnectClick;
 
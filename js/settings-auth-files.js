@@ -91,6 +91,7 @@ function toggleAuthFileSelection(filename, fileItem) {
 function updateActionButtons() {
     const hasSelection = selectedAuthFiles.size > 0;
     const allSelected = selectedAuthFiles.size === authFiles.length && authFiles.length > 0;
+    const readOnly = document.body.classList.contains('read-only');
     const currentTab = document.querySelector('.tab.active').getAttribute('data-tab');
     if (currentTab === 'auth') {
         resetBtn.style.display = 'none';
@@ -100,7 +101,8 @@ function updateActionButtons() {
         newDropdown.style.display = 'block';
         downloadBtn.style.display = 'block';
         selectAllBtn.textContent = allSelected ? 'Unselect All' : 'Select All';
-        deleteBtn.disabled = !hasSelection;
+        deleteBtn.disabled = readOnly || !hasSelection;
+        newBtn.disabled = readOnly;
         downloadBtn.disabled = !hasSelection;
     } else if (currentTab === 'access-token' || currentTab === 'api' || currentTab === 'openai' || currentTab === 'basic') {
         resetBtn.style.display = 'block';
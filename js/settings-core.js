@@ -108,6 +108,20 @@ function updateServerStatus() {
     }
 }
 
+// Reflect read-only mode: writes are already refused by configManager and the
+// backend, this makes the editing controls inert and says why
+async function applyReadOnlyMode(enabled) {
+    if (enabled === undefined) {
+        enabled = await configManager.isReadOnly();
+    }
+    document.body.classList.toggle('read-only', enabled);
+    const banner = document.getElementById('read-only-banner');
+    if (banner) {
+        banner.style.display = enabled ? 'block' : 'none';
+    }
+    updateActionButtons();
+}
+
 window.addEventListener('storage', (event) => {
     if (!event || event.key === null || event.key === 'base-url' || event.key === 'type') {
         updateServerStatus();
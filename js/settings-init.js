@@ -12,7 +12,7 @@ document.addEventListener('DOMContentLoaded', async () => {
         await initializeAutoStart();
         toggleLocalOnlyFields();
         updateServerStatus();
-        updateActionButtons();
+        await applyReadOnlyMode();
 
         const currentTabEl = document.querySelector('.tab.active');
         const currentTab = currentTabEl ? currentTabEl.getAttribute('data-tab') : 'basic';
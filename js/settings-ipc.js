@@ -37,6 +37,18 @@ if (window.__TAURI__?.event?.listen) {
         showProcessClosedError(`CLIProxyAPI process exited abnormally, exit code: ${errorData.code}`);
     });
 
+    window.__TAURI__.event.listen('read-only-changed', (event) => {
+        const data = event?.payload || {};
+        applyReadOnlyMode(!!data.enabled);
+    });
+
+    // Auth files changed on disk outside EasyCLI (token refresh, another login tool)
+    window.__TAURI__.event.listen('auth-files-changed', () => {
+        if (typeof loadAuthFiles === 'function') {
+            loadAuthFiles();
+        }
+    });
+
     window.__TAURI__.event.listen('cliproxyapi-restarted', (event) => {
         const data = event?.payload || {};
         console.log('CLIProxyAPI process restarted successfully:', data);